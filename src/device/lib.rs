@@ -53,6 +53,18 @@ pub enum MapAccess {
     RW
 }
 
+/// Identifies a `Device::submit` call, so completion can be tested for later with `Device::poll`.
+pub type SubmissionIndex = u64;
+
+/// An error that can occur when mapping a buffer asynchronously.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MapError {
+    /// The buffer is already mapped.
+    AlreadyMapped,
+    /// The requested access is not supported by the device.
+    Unsupported,
+}
+
 /// Unsafe operations for a buffer mapping
 pub trait RawMapping {
     /// Set the element at `index` to `val`. Not bounds-checked.
@@ -146,6 +158,140 @@ impl<'a, T: Copy, D: Device> Drop for RWMapping<'a, T, D> where D::Mapper: 'a {
     }
 }
 
+/// A handle to a readable map completed asynchronously, which can be sliced.
+///
+/// Unlike `ReadableMapping`, this cannot borrow `&'a mut D`: it is handed to its caller from
+/// inside a callback fired by `Device::poll(&mut self)`, and that borrow of the device does not
+/// outlive the `poll` call, while the mapping itself is expected to. It instead owns a boxed
+/// callback supplied by the backend at request time, and invokes it with the raw mapper on
+/// `Drop` — preserving the same "drop routes through `unmap_buffer_raw`" invariant as the
+/// synchronous mapping types, just via an owned callback rather than a borrowed device.
+pub struct AsyncReadableMapping<T: Copy, M: Clone + RawMapping> {
+    raw: M,
+    len: usize,
+    unmap: Box<FnMut(M)>,
+    phantom_t: PhantomData<T>
+}
+
+impl<T: Copy, M: Clone + RawMapping> Deref for AsyncReadableMapping<T, M> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { self.raw.to_slice(self.len) }
+    }
+}
+
+impl<T: Copy, M: Clone + RawMapping> Drop for AsyncReadableMapping<T, M> {
+    fn drop(&mut self) {
+        let raw = self.raw.clone();
+        (*self.unmap)(raw)
+    }
+}
+
+/// A handle to a writable map completed asynchronously, which only allows setting elements. See
+/// `AsyncReadableMapping` for why this owns a callback instead of borrowing the device.
+pub struct AsyncWritableMapping<T: Copy, M: Clone + RawMapping> {
+    raw: M,
+    len: usize,
+    unmap: Box<FnMut(M)>,
+    phantom_t: PhantomData<T>
+}
+
+impl<T: Copy, M: Clone + RawMapping> AsyncWritableMapping<T, M> {
+    /// Set a value in the buffer
+    pub fn set(&mut self, idx: usize, val: T) {
+        if idx >= self.len {
+            panic!("Tried to write out of bounds to an AsyncWritableMapping!")
+        }
+        unsafe { self.raw.set(idx, val); }
+    }
+}
+
+impl<T: Copy, M: Clone + RawMapping> Drop for AsyncWritableMapping<T, M> {
+    fn drop(&mut self) {
+        let raw = self.raw.clone();
+        (*self.unmap)(raw)
+    }
+}
+
+/// A handle to a complete readable/writable map completed asynchronously, which can be sliced
+/// both ways. See `AsyncReadableMapping` for why this owns a callback instead of borrowing the
+/// device.
+pub struct AsyncRWMapping<T: Copy, M: Clone + RawMapping> {
+    raw: M,
+    len: usize,
+    unmap: Box<FnMut(M)>,
+    phantom_t: PhantomData<T>
+}
+
+impl<T: Copy, M: Clone + RawMapping> Deref for AsyncRWMapping<T, M> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { self.raw.to_slice(self.len) }
+    }
+}
+
+impl<T: Copy, M: Clone + RawMapping> DerefMut for AsyncRWMapping<T, M> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { self.raw.to_mut_slice(self.len) }
+    }
+}
+
+impl<T: Copy, M: Clone + RawMapping> Drop for AsyncRWMapping<T, M> {
+    fn drop(&mut self) {
+        let raw = self.raw.clone();
+        (*self.unmap)(raw)
+    }
+}
+
+/// Flags controlling a persistent buffer mapping made via `Device::map_buffer_persistent`.
+/// Requires `Capabilities::immutable_storage_supported` and storage created with
+/// `StorageFlags::map_persistent`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MapFlags {
+    /// Make writes to the mapping visible to the GPU without an explicit
+    /// `PersistentMapping::flush_range` call. Requires storage created with
+    /// `StorageFlags::map_coherent`.
+    pub coherent: bool,
+}
+
+/// A handle to a mapping that stays valid across many draw calls, for streaming vertex/uniform
+/// data into a `Dynamic`/`Stream` buffer without re-mapping every frame. Callers are expected to
+/// advance through the mapping's sub-ranges in an N-buffered pattern (e.g. one sub-slice per
+/// frame in flight) to avoid the CPU and GPU racing on the same bytes.
+pub struct PersistentMapping<'a, T: Copy, D: 'a + Device> {
+    raw: D::Mapper,
+    len: usize,
+    coherent: bool,
+    device: &'a mut D,
+    phantom_t: PhantomData<T>
+}
+
+impl<'a, T: Copy, D: Device> PersistentMapping<'a, T, D> where D::Mapper: 'a {
+    /// Returns a mutable sub-slice of this mapping spanning `[offset, offset + len)` elements,
+    /// e.g. the sub-range owned by the current frame in an N-buffered streaming scheme.
+    pub fn sub_slice(&mut self, offset: usize, len: usize) -> &mut [T] {
+        assert!(offset + len <= self.len, "PersistentMapping::sub_slice out of bounds");
+        unsafe { &mut self.raw.to_mut_slice(self.len)[offset .. offset + len] }
+    }
+
+    /// Flush a byte range of the mapping so writes to it become visible to the GPU. A no-op when
+    /// the mapping was created with `MapFlags::coherent`.
+    pub fn flush_range(&mut self, offset_bytes: usize, size_bytes: usize) {
+        if !self.coherent {
+            self.device.flush_mapped_range_raw(self.raw.clone(), offset_bytes, size_bytes);
+        }
+    }
+}
+
+#[unsafe_destructor]
+impl<'a, T: Copy, D: Device> Drop for PersistentMapping<'a, T, D> where D::Mapper: 'a {
+    fn drop(&mut self) {
+        self.device.unmap_buffer_raw(self.raw.clone())
+    }
+}
+
 
 /// Treat a given slice as `&[u8]` for the given function call
 pub fn as_byte_slice<T>(slice: &[T]) -> &[u8] {
@@ -154,6 +300,83 @@ pub fn as_byte_slice<T>(slice: &[T]) -> &[u8] {
     unsafe { mem::transmute(slice) }
 }
 
+// Dmabuf (Linux "prime" fd) import/export is a unix-only, and in practice Linux-only, concept:
+// there is no equivalent file-descriptor-based memory sharing on Windows. The whole surface,
+// including the `Device::export_texture_fd`/`import_texture_fd` methods below, is therefore gated
+// behind `#[cfg(unix)]` so that backends targeting other platforms aren't required to link a
+// `close` symbol that doesn't exist there.
+#[cfg(unix)]
+use std::os::unix::io::RawFd;
+
+/// A packed four-byte pixel format code, as used by DRM/V4L2, e.g. `[b'X', b'R', b'2', b'4']`.
+#[cfg(unix)]
+pub type FourCC = [u8; 4];
+
+/// A dmabuf (Linux "prime" file descriptor) describing texture or surface memory that can be
+/// shared with another process or API without a copy. Owns `fd`: dropping the handle closes it,
+/// so call `into_raw_fd` first if ownership of the descriptor is being handed off elsewhere (e.g.
+/// sent over a socket to another process).
+#[cfg(unix)]
+#[derive(Debug)]
+pub struct DmabufHandle {
+    /// The raw, already-exported file descriptor backing the memory. Closed on `Drop` unless
+    /// taken via `into_raw_fd`.
+    pub fd: RawFd,
+    /// Pixel format of the shared memory.
+    pub format: FourCC,
+    /// Byte offset of each plane within the dmabuf.
+    pub offsets: Vec<usize>,
+    /// Byte stride of each plane.
+    pub strides: Vec<usize>,
+    /// Vendor-specific tiling/compression modifier, if the exporter applied one.
+    pub modifier: Option<u64>,
+}
+
+#[cfg(unix)]
+impl DmabufHandle {
+    /// Take ownership of the raw fd, preventing `Drop` from closing it. Use this when handing the
+    /// descriptor off to another process or API that takes over its lifetime.
+    pub fn into_raw_fd(mut self) -> RawFd {
+        let fd = self.fd;
+        self.fd = -1;
+        fd
+    }
+}
+
+#[cfg(unix)]
+impl Drop for DmabufHandle {
+    fn drop(&mut self) {
+        if self.fd >= 0 {
+            unsafe { close(self.fd); }
+        }
+    }
+}
+
+#[cfg(unix)]
+extern "C" {
+    fn close(fd: RawFd) -> i32;
+}
+
+/// An error that can occur when exporting a texture as a `DmabufHandle`.
+#[cfg(unix)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ExportError {
+    /// The device or driver does not support dmabuf export.
+    Unsupported,
+    /// The texture's internal format has no corresponding `FourCC` code.
+    UnsupportedFormat,
+}
+
+/// An error that can occur when importing a `DmabufHandle` as a texture.
+#[cfg(unix)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ImportError {
+    /// The device or driver does not support dmabuf import.
+    Unsupported,
+    /// The requested `tex::TextureInfo` is incompatible with the dmabuf's plane layout.
+    InvalidLayout,
+}
+
 /// Features that the device supports.
 #[derive(Copy, Debug)]
 #[allow(missing_docs)] // pretty self-explanatory fields!
@@ -165,6 +388,8 @@ pub struct Capabilities {
     pub max_vertex_attributes: usize,
 
     pub array_buffer_supported: bool,
+    pub dmabuf_supported: bool,
+    pub external_memory_supported: bool,
     pub fragment_output_supported: bool,
     pub immutable_storage_supported: bool,
     pub instance_base_supported: bool,
@@ -172,6 +397,7 @@ pub struct Capabilities {
     pub instance_rate_supported: bool,
     pub render_targets_supported: bool,
     pub sampler_objects_supported: bool,
+    pub transform_feedback_supported: bool,
     pub uniform_block_supported: bool,
     pub vertex_base_supported: bool,
 }
@@ -221,6 +447,48 @@ pub enum BufferUsage {
     Stream,
 }
 
+/// Flags controlling what an immutable-storage buffer may be used for after creation, mapping to
+/// the bits accepted by `glBufferStorage`. Unlike `BufferUsage`, these are not hints: the backend
+/// must reject (or, for `update_buffer_raw`, fail) any usage not requested here.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct StorageFlags {
+    /// Permit `update_buffer_raw` to respecify a subset of the storage after creation. Without
+    /// this flag, the storage is fully immutable once created.
+    pub dynamic_storage: bool,
+    /// Permit the storage to be mapped for reading.
+    pub map_read: bool,
+    /// Permit the storage to be mapped for writing.
+    pub map_write: bool,
+    /// Permit the storage to be mapped persistently via `Device::map_buffer_persistent`.
+    pub map_persistent: bool,
+    /// Permit a persistent mapping of this storage to use `MapFlags::coherent`.
+    pub map_coherent: bool,
+}
+
+/// A session capturing transform-feedback varyings into one or more buffers instead of
+/// rasterizing them, created by `Device::create_transform_feedback`. The varying names it was
+/// bound against are the ones passed as `feedback_varyings` to `create_program`.
+pub struct TransformFeedbackSession<R: Resources> {
+    /// The buffers bound to capture the feedback varyings, in varying order.
+    pub buffers: Vec<handle::Buffer<R, ()>>,
+}
+
+/// An error that can occur when starting a transform-feedback session.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TransformFeedbackError {
+    /// The device or driver does not support transform feedback.
+    Unsupported,
+}
+
+/// An error that can occur when issuing a device-side buffer or buffer/texture copy.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CopyError {
+    /// The requested range falls outside of the source or destination's `BufferInfo::size`.
+    OutOfBounds,
+    /// The source and destination regions of a same-buffer copy overlap.
+    Overlapping,
+}
+
 /// An information block that is immutable and associated with each buffer
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct BufferInfo {
@@ -254,8 +522,9 @@ pub trait Device {
     fn get_capabilities<'a>(&'a self) -> &'a Capabilities;
     /// Reset all the states to disabled/default
     fn reset_state(&mut self);
-    /// Submit a command buffer for execution
-    fn submit(&mut self, buffer: (&Self::CommandBuffer, &draw::DataBuffer));
+    /// Submit a command buffer for execution, returning the index it was submitted under so its
+    /// completion can later be tested for with `poll`.
+    fn submit(&mut self, buffer: (&Self::CommandBuffer, &draw::DataBuffer)) -> SubmissionIndex;
 
     // resource creation
     fn create_buffer_raw(&mut self, size: usize, usage: BufferUsage) -> handle::Buffer<Self::Resources, ()>;
@@ -266,15 +535,48 @@ pub trait Device {
     fn create_buffer_static<T: Copy>(&mut self, data: &[T]) -> handle::Buffer<Self::Resources, T> {
         self.create_buffer_static_raw(as_byte_slice(data)).cast()
     }
+    /// Create a buffer backed by immutable storage, initialized with `data`. Unlike
+    /// `create_buffer_static_raw`, the storage can never be reallocated, which lets the driver
+    /// place it more efficiently and is a prerequisite for `map_buffer_persistent`. Requires
+    /// `Capabilities::immutable_storage_supported`.
+    fn create_buffer_immutable_raw(&mut self, data: &[u8], flags: StorageFlags) -> handle::Buffer<Self::Resources, ()>;
+    /// Typed wrapper around `create_buffer_immutable_raw`.
+    fn create_buffer_immutable<T: Copy>(&mut self, data: &[T], flags: StorageFlags) -> handle::Buffer<Self::Resources, T> {
+        self.create_buffer_immutable_raw(as_byte_slice(data), flags).cast()
+    }
     fn create_array_buffer(&mut self) -> Result<handle::ArrayBuffer<Self::Resources>, ()>;
     fn create_shader(&mut self, stage: shade::Stage, code: &[u8]) ->
                      Result<handle::Shader<Self::Resources>, shade::CreateShaderError>;
-    fn create_program(&mut self, shaders: &[handle::Shader<Self::Resources>], targets: Option<&[&str]>) -> Result<handle::Program<Self::Resources>, ()>;
+    fn create_program(&mut self, shaders: &[handle::Shader<Self::Resources>], targets: Option<&[&str]>,
+                      feedback_varyings: Option<&[&str]>) -> Result<handle::Program<Self::Resources>, ()>;
     fn create_frame_buffer(&mut self) -> handle::FrameBuffer<Self::Resources>;
     fn create_surface(&mut self, info: tex::SurfaceInfo) -> Result<handle::Surface<Self::Resources>, tex::SurfaceError>;
     fn create_texture(&mut self, info: tex::TextureInfo) -> Result<handle::Texture<Self::Resources>, tex::TextureError>;
     fn create_sampler(&mut self, info: tex::SamplerInfo) -> handle::Sampler<Self::Resources>;
 
+    /// Begin a transform-feedback session, capturing the varyings a program was compiled with
+    /// `feedback_varyings` for into `buffers` instead of rasterizing them. Draws issued while the
+    /// session is active should have rasterizer discard enabled. Fails with
+    /// `TransformFeedbackError::Unsupported` unless
+    /// `Capabilities::transform_feedback_supported` is `true`.
+    ///
+    /// Incomplete: this only covers the `Device`-level half of the request. Recording
+    /// `begin_transform_feedback`/`end_transform_feedback` into a `draw::CommandBuffer` so draws
+    /// can be batched into the same submission as other commands is still unimplemented.
+    fn create_transform_feedback(&mut self, buffers: &[handle::Buffer<Self::Resources, ()>]) -> Result<TransformFeedbackSession<Self::Resources>, TransformFeedbackError>;
+
+    /// Export a texture's backing memory as a dmabuf file descriptor, for zero-copy sharing with
+    /// another process or API (e.g. scanout or a video decoder). Requires
+    /// `Capabilities::dmabuf_supported`. Unix-only: there is no dmabuf equivalent on other
+    /// platforms.
+    #[cfg(unix)]
+    fn export_texture_fd(&mut self, tex: &handle::Texture<Self::Resources>) -> Result<DmabufHandle, ExportError>;
+    /// Import a dmabuf file descriptor produced by another process or API as a texture, without
+    /// copying its contents. Requires `Capabilities::dmabuf_supported`. Unix-only: there is no
+    /// dmabuf equivalent on other platforms.
+    #[cfg(unix)]
+    fn import_texture_fd(&mut self, dmabuf: DmabufHandle, info: tex::TextureInfo) -> Result<handle::Texture<Self::Resources>, ImportError>;
+
     /// Return the framebuffer handle for the screen.
     fn get_main_frame_buffer(&self) -> handle::FrameBuffer<Self::Resources>;
 
@@ -289,7 +591,9 @@ pub trait Device {
     fn delete_texture(&mut self, handle::Texture<Self::Resources>);
     fn delete_sampler(&mut self, handle::Sampler<Self::Resources>);
 
-    /// Update the information stored in a specific buffer
+    /// Update the information stored in a specific buffer. For a buffer created with
+    /// `create_buffer_immutable_raw`, this panics unless `StorageFlags::dynamic_storage` was
+    /// requested at creation time.
     fn update_buffer_raw(&mut self, buf: handle::Buffer<Self::Resources, ()>, data: &[u8],
                          offset_bytes: usize);
     fn update_buffer<T: Copy>(&mut self, buf: handle::Buffer<Self::Resources, T>, data: &[T],
@@ -302,6 +606,37 @@ pub trait Device {
     fn map_buffer_writable<T: Copy>(&mut self, buf: handle::Buffer<Self::Resources, T>) -> WritableMapping<T, Self>;
     fn map_buffer_rw<T: Copy>(&mut self, buf: handle::Buffer<Self::Resources, T>) -> RWMapping<T, Self>;
 
+    /// Queue up a buffer mapping for read access that does not block the caller. The mapping is
+    /// only safe to hand back once the GPU has finished the submission that was current when this
+    /// was called, so the request is recorded against that `SubmissionIndex` and `callback` is
+    /// invoked with an `AsyncReadableMapping` the next time `poll` observes that submission has
+    /// completed. A buffer must not have an asynchronous mapping requested while another mapping
+    /// of it, sync or async, is still outstanding. See `AsyncReadableMapping` for why it owns its
+    /// own unmap callback rather than borrowing `&'a mut Self` the way `ReadableMapping` does.
+    fn map_buffer_readable_async<T: Copy>(&mut self, buf: handle::Buffer<Self::Resources, T>,
+                                 callback: Box<FnOnce(Result<AsyncReadableMapping<T, Self::Mapper>, MapError>) + Send>);
+    /// Write-access counterpart to `map_buffer_readable_async`.
+    fn map_buffer_writable_async<T: Copy>(&mut self, buf: handle::Buffer<Self::Resources, T>,
+                                 callback: Box<FnOnce(Result<AsyncWritableMapping<T, Self::Mapper>, MapError>) + Send>);
+    /// Read/write counterpart to `map_buffer_readable_async`.
+    fn map_buffer_rw_async<T: Copy>(&mut self, buf: handle::Buffer<Self::Resources, T>,
+                           callback: Box<FnOnce(Result<AsyncRWMapping<T, Self::Mapper>, MapError>) + Send>);
+    /// Walk the queue of pending asynchronous mappings, firing the callback of any whose
+    /// submission the GPU has since finished. When `block` is true, waits for the GPU to catch up
+    /// to the oldest pending request instead of returning immediately.
+    fn poll(&mut self, block: bool);
+
+    /// Map a buffer persistently, keeping it mapped across many draw calls. Panics unless
+    /// `Capabilities::immutable_storage_supported` is `true` and `buf` was created with
+    /// `create_buffer_immutable_raw` using storage flags compatible with `flags`.
+    fn map_buffer_persistent_raw(&mut self, buf: handle::Buffer<Self::Resources, ()>,
+                                 access: MapAccess, flags: MapFlags) -> Self::Mapper;
+    /// Flush a byte range of a persistent mapping so writes to it become visible to the GPU.
+    fn flush_mapped_range_raw(&mut self, map: Self::Mapper, offset_bytes: usize, size_bytes: usize);
+    /// Typed wrapper around `map_buffer_persistent_raw`.
+    fn map_buffer_persistent<T: Copy>(&mut self, buf: handle::Buffer<Self::Resources, T>,
+                             access: MapAccess, flags: MapFlags) -> PersistentMapping<T, Self>;
+
     /// Update the information stored in a texture
     fn update_texture_raw(&mut self, tex: &handle::Texture<Self::Resources>, img: &tex::ImageInfo,
                           data: &[u8]) -> Result<(), tex::TextureError>;
@@ -311,6 +646,151 @@ pub trait Device {
         self.update_texture_raw(tex, img, as_byte_slice(data))
     }
     fn generate_mipmap(&mut self, tex: &handle::Texture<Self::Resources>);
+
+    /// Toggle lazy zero-initialization tracking. While enabled, reading a range of a buffer or
+    /// texture that was never written (via `create_buffer_static`, `update_buffer_raw`, or a
+    /// device-side copy) is transparently zeroed on first read instead of exposing undefined
+    /// driver memory. Disable this once a caller is confident every read is preceded by a write,
+    /// to avoid its bookkeeping and clearing overhead.
+    fn set_zero_init_tracking(&mut self, enabled: bool);
+
+    /// Copy `size_bytes` from `src` to `dst` entirely on the GPU, without a CPU round-trip.
+    /// Bounds are validated against each buffer's `BufferInfo::size`.
+    ///
+    /// Incomplete: this only covers the immediate `Device`-level copy. Recording the equivalent
+    /// commands (`copy_buffer`, `copy_buffer_to_texture`, `copy_texture_to_buffer`) into a
+    /// `draw::CommandBuffer` so they can be batched into a submission alongside draws is still
+    /// unimplemented.
+    fn copy_buffer(&mut self, src: handle::Buffer<Self::Resources, ()>,
+                   dst: handle::Buffer<Self::Resources, ()>,
+                   src_offset_bytes: usize, dst_offset_bytes: usize,
+                   size_bytes: usize) -> Result<(), CopyError>;
+    /// Copy from a buffer into a texture region entirely on the GPU, e.g. to stream a texture
+    /// upload through a staging buffer.
+    fn copy_buffer_to_texture(&mut self, src: handle::Buffer<Self::Resources, ()>,
+                              src_offset_bytes: usize,
+                              dst: &handle::Texture<Self::Resources>,
+                              img: &tex::ImageInfo) -> Result<(), CopyError>;
+    /// Copy from a texture region into a buffer entirely on the GPU, e.g. for GPU-resident
+    /// readback staging.
+    fn copy_texture_to_buffer(&mut self, src: &handle::Texture<Self::Resources>,
+                              img: &tex::ImageInfo,
+                              dst: handle::Buffer<Self::Resources, ()>,
+                              dst_offset_bytes: usize) -> Result<(), CopyError>;
+}
+
+/// Tracks which byte ranges of a buffer or texture sub-resource have been written, so a device
+/// can transparently zero-fill the rest on first read instead of exposing undefined memory.
+/// To be held by device back ends alongside each `handle::Buffer`/`handle::Texture`.
+pub struct InitTracker {
+    /// Sorted, non-overlapping, non-adjacent `[start, end)` ranges that have been written.
+    ranges: Vec<(usize, usize)>,
+}
+
+impl InitTracker {
+    /// Create a tracker with nothing yet marked initialized.
+    pub fn new() -> InitTracker {
+        InitTracker { ranges: Vec::new() }
+    }
+
+    /// Mark `[start, end)` as written, merging it with any ranges it touches or overlaps.
+    pub fn mark_initialized(&mut self, start: usize, end: usize) {
+        if start >= end {
+            return;
+        }
+        let mut merged = (start, end);
+        let mut i = 0;
+        while i < self.ranges.len() {
+            let (s, e) = self.ranges[i];
+            if e < merged.0 || s > merged.1 {
+                i += 1;
+            } else {
+                merged = (merged.0.min(s), merged.1.max(e));
+                self.ranges.remove(i);
+            }
+        }
+        let pos = self.ranges.iter().position(|&(s, _)| s > merged.0).unwrap_or(self.ranges.len());
+        self.ranges.insert(pos, merged);
+    }
+
+    /// Returns the sub-ranges of `[start, end)` that are *not* yet initialized, in order. Each one
+    /// needs to be cleared to zero and then marked initialized before the read it backs proceeds.
+    pub fn uninitialized_ranges(&self, start: usize, end: usize) -> Vec<(usize, usize)> {
+        let mut gaps = Vec::new();
+        let mut cursor = start;
+        for &(s, e) in self.ranges.iter() {
+            if s >= end {
+                break;
+            }
+            if e <= cursor {
+                continue;
+            }
+            if s > cursor {
+                gaps.push((cursor, s.min(end)));
+            }
+            cursor = e.max(cursor);
+        }
+        if cursor < end {
+            gaps.push((cursor, end));
+        }
+        gaps
+    }
+
+    /// Returns `true` if every byte in `[start, end)` has already been marked initialized.
+    pub fn is_initialized(&self, start: usize, end: usize) -> bool {
+        self.uninitialized_ranges(start, end).is_empty()
+    }
+}
+
+/// Identifies a single mip level and array layer within a texture, for `TextureInitTracker`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TextureSubresource {
+    /// Mip level, with `0` being the full-size base level.
+    pub mip_level: u8,
+    /// Array layer, `0` for a non-array texture.
+    pub layer: u16,
+}
+
+/// Tracks initialized byte ranges per mip level/array layer of a texture, since each
+/// sub-resource has its own backing storage and can be written independently (e.g. by
+/// `update_texture_raw` or `copy_buffer_to_texture` targeting a single mip/layer). Sub-resources
+/// are only allocated an `InitTracker` the first time they're touched; an untouched one behaves
+/// as entirely uninitialized.
+pub struct TextureInitTracker {
+    subresources: Vec<(TextureSubresource, InitTracker)>,
+}
+
+impl TextureInitTracker {
+    /// Create a tracker with nothing yet marked initialized in any sub-resource.
+    pub fn new() -> TextureInitTracker {
+        TextureInitTracker { subresources: Vec::new() }
+    }
+
+    /// Mark `[start, end)` of `sub`'s data as written, allocating its `InitTracker` on first use.
+    pub fn mark_initialized(&mut self, sub: TextureSubresource, start: usize, end: usize) {
+        match self.subresources.iter().position(|&(s, _)| s == sub) {
+            Some(pos) => self.subresources[pos].1.mark_initialized(start, end),
+            None => {
+                let mut tracker = InitTracker::new();
+                tracker.mark_initialized(start, end);
+                self.subresources.push((sub, tracker));
+            }
+        }
+    }
+
+    /// Returns the sub-ranges of `sub`'s `[start, end)` that are *not* yet initialized, in order.
+    pub fn uninitialized_ranges(&self, sub: TextureSubresource, start: usize, end: usize) -> Vec<(usize, usize)> {
+        match self.subresources.iter().find(|&&(s, _)| s == sub) {
+            Some(&(_, ref tracker)) => tracker.uninitialized_ranges(start, end),
+            None if start < end => vec![(start, end)],
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns `true` if every byte in `sub`'s `[start, end)` has already been marked initialized.
+    pub fn is_initialized(&self, sub: TextureSubresource, start: usize, end: usize) -> bool {
+        self.uninitialized_ranges(sub, start, end).is_empty()
+    }
 }
 
 /// A service trait with methods for mapping already implemented.
@@ -325,6 +805,14 @@ pub trait MapFactory {
                     -> WritableMapping<T, Self>;
     fn map_read_write<T: Copy>(&mut self, Self::RawMapping, usize)
                       -> RWMapping<T, Self>;
+    fn map_persistent<T: Copy>(&mut self, Self::RawMapping, usize, bool)
+                      -> PersistentMapping<T, Self>;
+    fn map_readable_async<T: Copy>(&mut self, Self::RawMapping, usize, Box<FnMut(Self::RawMapping)>)
+                    -> AsyncReadableMapping<T, Self::RawMapping>;
+    fn map_writable_async<T: Copy>(&mut self, Self::RawMapping, usize, Box<FnMut(Self::RawMapping)>)
+                    -> AsyncWritableMapping<T, Self::RawMapping>;
+    fn map_read_write_async<T: Copy>(&mut self, Self::RawMapping, usize, Box<FnMut(Self::RawMapping)>)
+                      -> AsyncRWMapping<T, Self::RawMapping>;
 }
 
 
@@ -360,6 +848,50 @@ impl<D: Device> MapFactory for D {
             phantom_t: PhantomData,
         }
     }
+
+    fn map_persistent<T: Copy>(&mut self, map: <Self as MapFactory>::RawMapping,
+                      length: usize, coherent: bool) -> PersistentMapping<T, Self> {
+        PersistentMapping {
+            raw: map,
+            len: length,
+            coherent: coherent,
+            device: self,
+            phantom_t: PhantomData,
+        }
+    }
+
+    fn map_readable_async<T: Copy>(&mut self, map: <Self as MapFactory>::RawMapping,
+                    length: usize, unmap: Box<FnMut(<Self as MapFactory>::RawMapping)>)
+                    -> AsyncReadableMapping<T, Self::RawMapping> {
+        AsyncReadableMapping {
+            raw: map,
+            len: length,
+            unmap: unmap,
+            phantom_t: PhantomData,
+        }
+    }
+
+    fn map_writable_async<T: Copy>(&mut self, map: <Self as MapFactory>::RawMapping,
+                    length: usize, unmap: Box<FnMut(<Self as MapFactory>::RawMapping)>)
+                    -> AsyncWritableMapping<T, Self::RawMapping> {
+        AsyncWritableMapping {
+            raw: map,
+            len: length,
+            unmap: unmap,
+            phantom_t: PhantomData,
+        }
+    }
+
+    fn map_read_write_async<T: Copy>(&mut self, map: <Self as MapFactory>::RawMapping,
+                      length: usize, unmap: Box<FnMut(<Self as MapFactory>::RawMapping)>)
+                      -> AsyncRWMapping<T, Self::RawMapping> {
+        AsyncRWMapping {
+            raw: map,
+            len: length,
+            unmap: unmap,
+            phantom_t: PhantomData,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -405,4 +937,54 @@ mod test {
     fn test_buffer_zero_len() {
         let _ = mock_buffer::<()>(0).len();
     }
+
+    #[test]
+    fn test_init_tracker_merges_adjacent_ranges() {
+        let mut tracker = super::InitTracker::new();
+        tracker.mark_initialized(0, 4);
+        tracker.mark_initialized(4, 8);
+        assert!(tracker.is_initialized(0, 8));
+    }
+
+    #[test]
+    fn test_init_tracker_reports_gaps() {
+        let mut tracker = super::InitTracker::new();
+        tracker.mark_initialized(4, 8);
+        assert!(!tracker.is_initialized(0, 8));
+        assert_eq!(tracker.uninitialized_ranges(0, 8), vec![(0, 4)]);
+    }
+
+    #[test]
+    fn test_init_tracker_bridges_separated_ranges() {
+        let mut tracker = super::InitTracker::new();
+        tracker.mark_initialized(0, 2);
+        tracker.mark_initialized(6, 8);
+        assert_eq!(tracker.uninitialized_ranges(0, 8), vec![(2, 6)]);
+        tracker.mark_initialized(2, 6);
+        assert!(tracker.is_initialized(0, 8));
+        assert_eq!(tracker.uninitialized_ranges(0, 8), Vec::new());
+    }
+
+    #[test]
+    fn test_init_tracker_multiple_stored_ranges() {
+        let mut tracker = super::InitTracker::new();
+        tracker.mark_initialized(0, 2);
+        tracker.mark_initialized(4, 6);
+        tracker.mark_initialized(8, 10);
+        assert!(tracker.is_initialized(0, 2));
+        assert!(tracker.is_initialized(4, 6));
+        assert!(!tracker.is_initialized(0, 10));
+        assert_eq!(tracker.uninitialized_ranges(0, 10), vec![(2, 4), (6, 8)]);
+    }
+
+    #[test]
+    fn test_texture_init_tracker_is_per_subresource() {
+        let mut tracker = super::TextureInitTracker::new();
+        let base = super::TextureSubresource { mip_level: 0, layer: 0 };
+        let mip1 = super::TextureSubresource { mip_level: 1, layer: 0 };
+        tracker.mark_initialized(base, 0, 16);
+        assert!(tracker.is_initialized(base, 0, 16));
+        assert!(!tracker.is_initialized(mip1, 0, 16));
+        assert_eq!(tracker.uninitialized_ranges(mip1, 0, 16), vec![(0, 16)]);
+    }
 }